@@ -9,14 +9,23 @@
 //   reqwest = { version = "0.11", features = ["blocking", "json"] }
 //   serde = { version = "1", features = ["derive"] }
 //   serde_json = "1"
+//   clap = { version = "4", features = ["derive"] }
 //
 // Run:
 //   cargo run
-//   cargo run -- --location London
-//   cargo run -- --location "New York" --days 3
+//   cargo run -- --city London
+//   cargo run -- --city "New York" --days 3
+//   cargo run -- --zipcode 94043 --country-code US --units imperial
+//   cargo run -- --lat 51.5072 --lon -0.1276 --lang es
+//   cargo run -- --city London --watch 600        (live dashboard, refresh every 600s)
+//   cargo run -- --city London --format " $icon $temp°C $humidity% "
+//   cargo run -- --autolocate                     (geolocate from your IP)
+//   cargo run -- --city London --output json      (machine-readable report)
+//   cargo run -- --city London --hourly 3 --forecast-hours 12
 //
 // Set your API key:
 //   export WWO_API_KEY="your_key_here"
+//   (or pass --api-key on the command line)
 //
 // Get a free key at:
 //   https://www.worldweatheronline.com/weather-api/
@@ -24,14 +33,158 @@
 use std::collections::HashMap;
 use std::env;
 use std::process;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
+use clap::{CommandFactory, Parser, ValueEnum};
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 // ─── CONFIG ───────────────────────────────────────────────────────────────────
 
 const BASE_URL: &str = "https://api.worldweatheronline.com/premium/v1/weather.ashx";
 
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum Units {
+    Metric,
+    Imperial,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum OutputFormat {
+    Json,
+}
+
+/// World Weather Online — Weather Dashboard
+#[derive(Parser, Debug)]
+#[command(name = "wwo", version, about)]
+struct Opts {
+    /// Postal/zip code — pair with --country-code
+    #[arg(long)]
+    zipcode: Option<String>,
+
+    /// ISO country code for --zipcode, e.g. "US"
+    #[arg(long = "country-code")]
+    country_code: Option<String>,
+
+    /// City name, e.g. "New York"
+    #[arg(long)]
+    city: Option<String>,
+
+    /// Latitude — must be paired with --lon
+    #[arg(long, allow_hyphen_values = true)]
+    lat: Option<f64>,
+
+    /// Longitude — must be paired with --lat
+    #[arg(long, allow_hyphen_values = true)]
+    lon: Option<f64>,
+
+    /// Location, kept for backwards-compatible positional usage
+    location: Option<String>,
+
+    /// Number of forecast days
+    #[arg(long, default_value_t = 5)]
+    days: u8,
+
+    /// Display units
+    #[arg(long, value_enum, default_value_t = Units::Metric)]
+    units: Units,
+
+    /// Response language code, e.g. "es", "fr"
+    #[arg(long)]
+    lang: Option<String>,
+
+    /// API key — falls back to the WWO_API_KEY environment variable
+    #[arg(long = "api-key")]
+    api_key: Option<String>,
+
+    /// Resolve location from the caller's IP instead of a location argument
+    #[arg(long)]
+    autolocate: bool,
+
+    /// Poll and redraw every N seconds instead of printing once
+    #[arg(long)]
+    watch: Option<u64>,
+
+    /// Custom output template, e.g. " $icon $temp $humidity "
+    #[arg(long)]
+    format: Option<String>,
+
+    /// Secondary output template, printed alongside --format
+    #[arg(long = "format-alt")]
+    format_alt: Option<String>,
+
+    /// Machine-readable output format
+    #[arg(long, value_enum)]
+    output: Option<OutputFormat>,
+
+    /// Hourly forecast interval in hours: 1, 3, 6, or 12
+    #[arg(long)]
+    hourly: Option<u8>,
+
+    /// With --hourly, how many hourly slots to show, counted from the first
+    /// slot (00:00) of the first forecast day, not from the current time
+    #[arg(long = "forecast-hours", default_value_t = 24)]
+    forecast_hours: usize,
+}
+
+impl Opts {
+    fn validate(&self) {
+        if self.lat.is_some() != self.lon.is_some() {
+            Opts::command()
+                .error(
+                    clap::error::ErrorKind::ArgumentConflict,
+                    "--lat and --lon must be provided together",
+                )
+                .exit();
+        }
+        if let Some(hourly) = self.hourly {
+            if !matches!(hourly, 1 | 3 | 6 | 12) {
+                Opts::command()
+                    .error(
+                        clap::error::ErrorKind::InvalidValue,
+                        "--hourly must be one of 1, 3, 6, 12",
+                    )
+                    .exit();
+            }
+            if self.output == Some(OutputFormat::Json) {
+                Opts::command()
+                    .error(
+                        clap::error::ErrorKind::ArgumentConflict,
+                        "--hourly cannot be combined with --output json (the JSON report assumes a one-summary-per-day forecast)",
+                    )
+                    .exit();
+            }
+        }
+    }
+
+    /// The `tp` query parameter to send to `fetch_weather`.
+    fn tp(&self) -> String {
+        self.hourly.map(|h| h.to_string()).unwrap_or_else(|| "24".to_string())
+    }
+
+    /// The `q` query parameter to send to `fetch_weather`.
+    fn location_query(&self) -> String {
+        if let (Some(lat), Some(lon)) = (self.lat, self.lon) {
+            return format!("{},{}", lat, lon);
+        }
+        if let Some(zipcode) = &self.zipcode {
+            return match &self.country_code {
+                Some(cc) => format!("{},{}", zipcode, cc),
+                None => zipcode.clone(),
+            };
+        }
+        if let Some(city) = &self.city {
+            return city.clone();
+        }
+        if let Some(location) = &self.location {
+            return location.clone();
+        }
+        "London".to_string()
+    }
+}
+
 fn get_icon(description: &str) -> &'static str {
     let desc = description.to_lowercase();
     if desc.contains("sunny")         { return "☀️"; }
@@ -70,8 +223,10 @@ struct CurrentCondition {
     #[serde(rename = "temp_C")]   temp_c: String,
     #[serde(rename = "temp_F")]   temp_f: String,
     #[serde(rename = "FeelsLikeC")] feels_like_c: String,
+    #[serde(rename = "FeelsLikeF")] feels_like_f: String,
     humidity: String,
     #[serde(rename = "windspeedMiles")] windspeed_miles: String,
+    #[serde(rename = "windspeedKmph")] windspeed_kmph: String,
     #[serde(rename = "winddir16Point")] winddir: String,
     #[serde(rename = "uvIndex")]  uv_index: String,
     visibility: String,
@@ -82,12 +237,17 @@ struct CurrentCondition {
 struct DayForecast {
     date: String,
     #[serde(rename = "maxtempC")] max_temp_c: String,
+    #[serde(rename = "maxtempF")] max_temp_f: String,
     #[serde(rename = "mintempC")] min_temp_c: String,
+    #[serde(rename = "mintempF")] min_temp_f: String,
     hourly: Vec<HourlyData>,
 }
 
 #[derive(Deserialize, Debug)]
 struct HourlyData {
+    time: String,
+    #[serde(rename = "tempC")] temp_c: String,
+    #[serde(rename = "tempF")] temp_f: String,
     #[serde(rename = "weatherDesc")] weather_desc: Vec<Description>,
     chanceofrain: Option<String>,
 }
@@ -103,10 +263,110 @@ struct Description {
     value: String,
 }
 
+#[derive(Deserialize, Debug)]
+struct GeoLocation {
+    city: String,
+    lat: f64,
+    lon: f64,
+}
+
+// ─── JSON REPORT ──────────────────────────────────────────────────────────────
+//
+// A normalized, typed view of `WeatherData` for `--output json`. The WWO
+// structs above mirror the API's awkward, stringly-typed field names; this
+// is the stable shape we're willing to commit to for scripting consumers.
+
+#[derive(Serialize, Debug)]
+struct Report {
+    data_source: String,
+    location: String,
+    current: ReportCurrent,
+    forecast: Vec<ReportDay>,
+}
+
+#[derive(Serialize, Debug)]
+struct ReportCurrent {
+    icon: String,
+    description: String,
+    temp_c: f64,
+    temp_f: f64,
+    feels_like_c: f64,
+    humidity: u8,
+    wind_speed_mph: f64,
+    wind_speed_kmph: f64,
+    wind_dir: String,
+    uv_index: f64,
+    visibility_km: f64,
+}
+
+#[derive(Serialize, Debug)]
+struct ReportDay {
+    date: String,
+    max_temp_c: f64,
+    min_temp_c: f64,
+    chance_of_rain: Option<u8>,
+    description: String,
+}
+
+fn parse_num<T: std::str::FromStr + Default>(s: &str) -> T {
+    s.parse().unwrap_or_default()
+}
+
+impl From<WeatherData> for Report {
+    fn from(data: WeatherData) -> Self {
+        let location = data
+            .nearest_area
+            .as_ref()
+            .and_then(|a| a.first())
+            .map(|area| format!("{}, {}", area.area_name[0].value, area.country[0].value))
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let current = &data.current_condition[0];
+        let current = ReportCurrent {
+            icon: get_icon(&current.weather_desc[0].value).to_string(),
+            description: current.weather_desc[0].value.clone(),
+            temp_c: parse_num(&current.temp_c),
+            temp_f: parse_num(&current.temp_f),
+            feels_like_c: parse_num(&current.feels_like_c),
+            humidity: parse_num(&current.humidity),
+            wind_speed_mph: parse_num(&current.windspeed_miles),
+            wind_speed_kmph: parse_num(&current.windspeed_kmph),
+            wind_dir: current.winddir.clone(),
+            uv_index: parse_num(&current.uv_index),
+            visibility_km: parse_num(&current.visibility),
+        };
+
+        let forecast = data
+            .weather
+            .iter()
+            .map(|day| ReportDay {
+                date: day.date.clone(),
+                max_temp_c: parse_num(&day.max_temp_c),
+                min_temp_c: parse_num(&day.min_temp_c),
+                chance_of_rain: day.hourly[0].chanceofrain.as_deref().map(parse_num),
+                description: day.hourly[0].weather_desc[0].value.clone(),
+            })
+            .collect();
+
+        Report {
+            data_source: "World Weather Online — https://www.worldweatheronline.com".to_string(),
+            location,
+            current,
+            forecast,
+        }
+    }
+}
+
 
 // ─── API CALL ─────────────────────────────────────────────────────────────────
 
-fn fetch_weather(location: &str, days: u8, api_key: &str) -> Result<WeatherData, Box<dyn std::error::Error>> {
+fn fetch_weather(
+    location: &str,
+    days: u8,
+    api_key: &str,
+    lang: Option<&str>,
+    tp: &str,
+) -> Result<WeatherData, Box<dyn std::error::Error + Send + Sync>> {
     if api_key == "your_api_key_here" {
         eprintln!("❌  Please set your API key!");
         eprintln!("    export WWO_API_KEY='your_key_here'");
@@ -119,47 +379,97 @@ fn fetch_weather(location: &str, days: u8, api_key: &str) -> Result<WeatherData,
         .user_agent("WWO-Rust-Client/1.0")
         .build()?;
 
-    let response = client.get(BASE_URL)
-        .query(&[
-            ("key",             api_key),
-            ("q",               location),
-            ("format",          "json"),
-            ("num_of_days",     &days.to_string()),
-            ("tp",              "24"),
-            ("includelocation", "yes"),
-            ("cc",              "yes"),
-        ])
-        .send()?;
+    let days_str = days.to_string();
+    let mut params = vec![
+        ("key",             api_key),
+        ("q",               location),
+        ("format",          "json"),
+        ("num_of_days",     days_str.as_str()),
+        ("tp",              tp),
+        ("includelocation", "yes"),
+        ("cc",              "yes"),
+    ];
+    if let Some(lang) = lang {
+        params.push(("lang", lang));
+    }
+
+    let response = client.get(BASE_URL).query(&params).send()?;
 
     if !response.status().is_success() {
-        eprintln!("❌  HTTP Error: {}", response.status());
-        process::exit(1);
+        return Err(format!("HTTP Error: {}", response.status()).into());
     }
 
     let result: WeatherResponse = response.json()?;
     Ok(result.data)
 }
 
+// ─── AUTOLOCATE ───────────────────────────────────────────────────────────────
+
+const GEO_URL: &str = "http://ip-api.com/json/";
+
+// Resolves the caller's approximate location via IP geolocation, returning a
+// "lat,lon" string suitable for `fetch_weather`'s `q` parameter. Returns
+// `None` on any network or parse failure so callers can fall back quietly.
+fn resolve_location() -> Option<String> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(5))
+        .user_agent("WWO-Rust-Client/1.0")
+        .build()
+        .ok()?;
+
+    let response = client.get(GEO_URL).send().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let geo: GeoLocation = response.json().ok()?;
+    eprintln!("📍  Auto-located to {} ({:.4},{:.4})", geo.city, geo.lat, geo.lon);
+    Some(format!("{},{}", geo.lat, geo.lon))
+}
+
 
 // ─── DISPLAY ──────────────────────────────────────────────────────────────────
 
-fn display_current(current: &CurrentCondition, location_name: &str) {
+// Trend is always derived from the Celsius reading regardless of display
+// units — it's a shape (rising/falling/steady), not a unit-sensitive value.
+fn get_trend(current: i32, upcoming: i32) -> char {
+    let diff = upcoming - current;
+    if diff > 1 {
+        '↗'
+    } else if diff < -1 {
+        '↘'
+    } else {
+        '→'
+    }
+}
+
+fn display_current(current: &CurrentCondition, location_name: &str, units: Units, trend: Option<char>) {
     let desc = &current.weather_desc[0].value;
     let icon = get_icon(desc);
 
+    let (temp, feels, temp_unit) = match units {
+        Units::Metric => (&current.temp_c, &current.feels_like_c, "°C"),
+        Units::Imperial => (&current.temp_f, &current.feels_like_f, "°F"),
+    };
+    let (wind, wind_unit) = match units {
+        Units::Metric => (&current.windspeed_kmph, "km/h"),
+        Units::Imperial => (&current.windspeed_miles, "mph"),
+    };
+    let trend_suffix = trend.map(|t| format!(" {}", t)).unwrap_or_default();
+
     println!("\n{}", "─".repeat(50));
     println!("📍 {} — Right Now", location_name);
     println!("{}", "─".repeat(50));
     println!("{}  {}", icon, desc);
-    println!("🌡️  Temperature : {}°C / {}°F (Feels like {}°C)", current.temp_c, current.temp_f, current.feels_like_c);
+    println!("🌡️  Temperature : {}{temp_unit}{trend_suffix} (Feels like {}{temp_unit})", temp, feels);
     println!("💧  Humidity    : {}%", current.humidity);
-    println!("💨  Wind        : {} mph {}", current.windspeed_miles, current.winddir);
+    println!("💨  Wind        : {} {wind_unit} {}", wind, current.winddir);
     println!("👁️  Visibility  : {} km", current.visibility);
     println!("☀️  UV Index    : {}", current.uv_index);
     println!("{}", "─".repeat(50));
 }
 
-fn display_forecast(weather_days: &[DayForecast]) {
+fn display_forecast(weather_days: &[DayForecast], units: Units) {
     println!("\n📅 Forecast\n");
     println!("{:<14} {:<25} {:>7} {:>7} {:>7}", "Date", "Conditions", "High", "Low", "Rain%");
     println!("{}", "─".repeat(65));
@@ -169,13 +479,17 @@ fn display_forecast(weather_days: &[DayForecast]) {
         let icon = get_icon(desc);
         let rain = day.hourly[0].chanceofrain.as_deref().unwrap_or("N/A");
         let cond = format!("{} {}", icon, desc);
+        let (high, low, unit) = match units {
+            Units::Metric => (&day.max_temp_c, &day.min_temp_c, "°C"),
+            Units::Imperial => (&day.max_temp_f, &day.min_temp_f, "°F"),
+        };
 
         println!(
             "{:<14} {:<25} {:>7} {:>7} {:>7}",
             &day.date,
             cond,
-            format!("{}°C", day.max_temp_c),
-            format!("{}°C", day.min_temp_c),
+            format!("{high}{unit}"),
+            format!("{low}{unit}"),
             format!("{}%", rain),
         );
     }
@@ -184,30 +498,234 @@ fn display_forecast(weather_days: &[DayForecast]) {
 }
 
 
-// ─── MAIN ─────────────────────────────────────────────────────────────────────
+// WWO encodes hourly slot times as the hour*100, e.g. "300" -> 03:00.
+fn format_hour_time(raw: &str) -> String {
+    let v: u32 = raw.parse().unwrap_or(0);
+    format!("{:02}:{:02}", v / 100, v % 100)
+}
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let location = args.get(1).map(String::as_str).unwrap_or("London");
-    let days: u8 = args.get(2).and_then(|d| d.parse().ok()).unwrap_or(5);
+fn display_hourly(weather_days: &[DayForecast], hours: usize, units: Units) {
+    println!("\n⏱️  Hourly Forecast\n");
+    println!("{:<7} {:<4} {:<25} {:>7} {:>7}", "Time", "", "Conditions", "Temp", "Rain%");
+    println!("{}", "─".repeat(55));
+
+    let mut shown = 0;
+    'days: for day in weather_days {
+        for hour in &day.hourly {
+            if shown >= hours {
+                break 'days;
+            }
+
+            let desc = &hour.weather_desc[0].value;
+            let icon = get_icon(desc);
+            let (temp, unit) = match units {
+                Units::Metric => (&hour.temp_c, "°C"),
+                Units::Imperial => (&hour.temp_f, "°F"),
+            };
+            let rain = hour.chanceofrain.as_deref().unwrap_or("N/A");
+
+            println!(
+                "{:<7} {:<4} {:<25} {:>7} {:>7}",
+                format_hour_time(&hour.time),
+                icon,
+                desc,
+                format!("{temp}{unit}"),
+                format!("{rain}%"),
+            );
+            shown += 1;
+        }
+    }
 
-    let api_key = env::var("WWO_API_KEY").unwrap_or_else(|_| "your_api_key_here".to_string());
+    println!("{}", "─".repeat(55));
+}
 
-    println!("\n🌍 World Weather Online — fetching weather for {}...", location);
+fn resolve_location_name(data: &WeatherData, fallback: &str) -> String {
+    data.nearest_area
+        .as_ref()
+        .and_then(|a| a.first())
+        .map(|area| format!("{}, {}", area.area_name[0].value, area.country[0].value))
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+fn render_snapshot(data: &WeatherData, location: &str, units: Units, forecast_hours: Option<usize>) {
+    let location_name = resolve_location_name(data, location);
+    let current = &data.current_condition[0];
+    let trend = data.weather.first().map(|day| {
+        get_trend(parse_num(&current.temp_c), parse_num(&day.max_temp_c))
+    });
+
+    display_current(current, &location_name, units, trend);
+    match forecast_hours {
+        Some(hours) => display_hourly(&data.weather, hours, units),
+        None => display_forecast(&data.weather, units),
+    }
+    println!("\nData by World Weather Online — https://www.worldweatheronline.com\n");
+}
+
+fn render_snapshot_formatted(data: &WeatherData, location: &str, format: &str, format_alt: Option<&str>) {
+    let location_name = resolve_location_name(data, location);
+    let vars = build_template_vars(&data.current_condition[0], &location_name);
+    println!("{}", render_format(format, &vars));
+    if let Some(alt) = format_alt {
+        println!("{}", render_format(alt, &vars));
+    }
+}
+
+// ─── FORMAT TEMPLATES ─────────────────────────────────────────────────────────
+//
+// Tokens: $icon $temp $temp_f $feels $humidity $wind $winddir $uv $vis $desc
+// $location. `$$` escapes a literal dollar sign. Unknown tokens render empty.
+
+fn build_template_vars(current: &CurrentCondition, location_name: &str) -> HashMap<&'static str, String> {
+    let mut vars = HashMap::new();
+    vars.insert("icon", get_icon(&current.weather_desc[0].value).to_string());
+    vars.insert("temp", current.temp_c.clone());
+    vars.insert("temp_f", current.temp_f.clone());
+    vars.insert("feels", current.feels_like_c.clone());
+    vars.insert("humidity", current.humidity.clone());
+    vars.insert("wind", current.windspeed_miles.clone());
+    vars.insert("winddir", current.winddir.clone());
+    vars.insert("uv", current.uv_index.clone());
+    vars.insert("vis", current.visibility.clone());
+    vars.insert("desc", current.weather_desc[0].value.clone());
+    vars.insert("location", location_name.to_string());
+    vars
+}
+
+fn render_format(template: &str, vars: &HashMap<&str, String>) -> String {
+    let chars: Vec<char> = template.chars().collect();
+    let mut out = String::with_capacity(chars.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] != '$' {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        match chars.get(i + 1) {
+            Some('$') => {
+                out.push('$');
+                i += 2;
+            }
+            Some(&c) if c.is_alphabetic() || c == '_' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+                let name: String = chars[start..end].iter().collect();
+                out.push_str(vars.get(name.as_str()).map(String::as_str).unwrap_or(""));
+                i = end;
+            }
+            _ => {
+                out.push('$');
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
 
-    match fetch_weather(location, days, &api_key) {
-        Ok(data) => {
-            let location_name = data.nearest_area
-                .as_ref()
-                .and_then(|a| a.first())
-                .map(|area| format!("{}, {}", area.area_name[0].value, area.country[0].value))
-                .unwrap_or_else(|| location.to_string());
+// ─── WATCH MODE ───────────────────────────────────────────────────────────────
 
-            display_current(&data.current_condition[0], &location_name);
-            display_forecast(&data.weather);
+fn clear_screen() {
+    print!("\x1B[2J\x1B[1;1H");
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_watch(
+    location: String,
+    days: u8,
+    api_key: String,
+    lang: Option<String>,
+    tp: String,
+    interval: Duration,
+    units: Units,
+    format: Option<&str>,
+    format_alt: Option<&str>,
+    forecast_hours: Option<usize>,
+) {
+    let (tx, rx) = mpsc::channel();
+    let fetch_location = location.clone();
+
+    thread::spawn(move || loop {
+        let result = fetch_weather(&fetch_location, days, &api_key, lang.as_deref(), &tp);
+        if tx.send(result).is_err() {
+            break; // receiver dropped — main thread is gone, stop polling
+        }
+        thread::sleep(interval);
+    });
+
+    loop {
+        match rx.recv() {
+            Ok(Ok(data)) => {
+                clear_screen();
+                match format {
+                    Some(fmt) => render_snapshot_formatted(&data, &location, fmt, format_alt),
+                    None => render_snapshot(&data, &location, units, forecast_hours),
+                }
+            }
+            Ok(Err(e)) => {
+                clear_screen();
+                eprintln!("❌  Error: {} (retrying in {}s)", e, interval.as_secs());
+            }
+            Err(_) => break, // worker thread exited
+        }
+    }
+}
+
+// ─── MAIN ─────────────────────────────────────────────────────────────────────
+
+fn main() {
+    let opts = Opts::parse();
+    opts.validate();
+
+    let api_key = opts
+        .api_key
+        .clone()
+        .or_else(|| env::var("WWO_API_KEY").ok())
+        .unwrap_or_else(|| "your_api_key_here".to_string());
+
+    let location = if opts.autolocate {
+        resolve_location().unwrap_or_else(|| opts.location_query())
+    } else {
+        opts.location_query()
+    };
+    let tp = opts.tp();
+    let forecast_hours = opts.hourly.map(|_| opts.forecast_hours);
+
+    if let Some(seconds) = opts.watch {
+        let interval = Duration::from_secs(seconds);
+        println!("\n🌍 World Weather Online — watching {} every {}s (Ctrl+C to stop)...", location, seconds);
+        run_watch(
+            location,
+            opts.days,
+            api_key,
+            opts.lang.clone(),
+            tp,
+            interval,
+            opts.units,
+            opts.format.as_deref(),
+            opts.format_alt.as_deref(),
+            forecast_hours,
+        );
+        return;
+    }
+
+    println!("\n🌍 World Weather Online — fetching weather for {}...", location);
 
-            println!("\nData by World Weather Online — https://www.worldweatheronline.com\n");
+    match fetch_weather(&location, opts.days, &api_key, opts.lang.as_deref(), &tp) {
+        Ok(data) if opts.output == Some(OutputFormat::Json) => {
+            let report = Report::from(data);
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
         }
+        Ok(data) => match opts.format.as_deref() {
+            Some(fmt) => render_snapshot_formatted(&data, &location, fmt, opts.format_alt.as_deref()),
+            None => render_snapshot(&data, &location, opts.units, forecast_hours),
+        },
         Err(e) => {
             eprintln!("❌  Error: {}", e);
             process::exit(1);